@@ -7,8 +7,10 @@ use crate::bundle::Settings;
 use crate::utils::{ self, fs_utils, CommandExt, };
 use std::{
   cmp::min,
+  collections::hash_map::DefaultHasher,
   ffi::OsStr,
   fs::{self, File},
+  hash::{Hash, Hasher},
   io::{self, BufWriter},
   path::{Path, PathBuf},
   process::Command,
@@ -16,6 +18,63 @@ use std::{
 
 use image::GenericImageView;
 
+// Hashes the contents of `path` into `hasher`, recursing into directories
+// (sorted for determinism) so a fingerprint can cover both single icon
+// files and `.icon`/`.iconset`-style directories.
+fn hash_path_contents(path: &Path, hasher: &mut impl Hasher) -> crate::Result<()> {
+  if path.is_dir() {
+    let mut entries: Vec<PathBuf> = fs::read_dir(path)?
+      .map(|entry| entry.map(|entry| entry.path()))
+      .collect::<io::Result<Vec<_>>>()?;
+    entries.sort();
+    for entry in entries {
+      hash_path_contents(&entry, hasher)?;
+    }
+  } else {
+    path.hash(hasher);
+    fs::read(path)?.hash(hasher);
+  }
+  Ok(())
+}
+
+// Computes a fingerprint over the contents of `paths` plus any `extra`
+// context (e.g. a tool version string), so callers can skip expensive
+// regeneration when none of it has changed since the last build.
+fn compute_fingerprint(paths: &[PathBuf], extra: &[&str]) -> crate::Result<String> {
+  let mut hasher = DefaultHasher::new();
+  let mut paths = paths.to_vec();
+  paths.sort();
+  for path in &paths {
+    hash_path_contents(path, &mut hasher)?;
+  }
+  for value in extra {
+    value.hash(&mut hasher);
+  }
+  Ok(format!("{:016x}", hasher.finish()))
+}
+
+// Where the fingerprint for a generated artifact at `output_path` is stored.
+fn fingerprint_path_for(output_path: &Path) -> PathBuf {
+  let mut fingerprint_path = output_path.as_os_str().to_owned();
+  fingerprint_path.push(".fingerprint");
+  PathBuf::from(fingerprint_path)
+}
+
+// Whether `output_path` already exists and was produced from `fingerprint`.
+fn fingerprint_matches(output_path: &Path, fingerprint: &str) -> bool {
+  if !output_path.exists() {
+    return false;
+  }
+  fs::read_to_string(fingerprint_path_for(output_path))
+    .map(|existing| existing == fingerprint)
+    .unwrap_or(false)
+}
+
+fn write_fingerprint(output_path: &Path, fingerprint: &str) -> crate::Result<()> {
+  fs::write(fingerprint_path_for(output_path), fingerprint)?;
+  Ok(())
+}
+
 // Given a list of icon files, try to produce an ICNS file in the out_dir
 // and return the path to it.  Returns `Ok(None)` if no usable icons
 // were provided.
@@ -35,6 +94,19 @@ pub fn create_icns_file(out_dir: &Path, settings: &Settings) -> crate::Result<Op
     }
   }
 
+  // Skip regenerating the ICNS if none of the input icons' contents have
+  // changed since the last time we packed them.
+  let mut dest_path = out_dir.to_path_buf();
+  dest_path.push(settings.product_name());
+  dest_path.set_extension("icns");
+  let icon_paths = settings
+    .icon_files()
+    .collect::<crate::Result<Vec<PathBuf>>>()?;
+  let fingerprint = compute_fingerprint(&icon_paths, &[])?;
+  if fingerprint_matches(&dest_path, &fingerprint) {
+    return Ok(Some(dest_path));
+  }
+
   // Otherwise, read available images and pack them into a new ICNS file.
   let mut family = icns::IconFamily::new();
 
@@ -43,25 +115,32 @@ pub fn create_icns_file(out_dir: &Path, settings: &Settings) -> crate::Result<Op
     density: u32,
     family: &mut icns::IconFamily,
   ) -> io::Result<()> {
-    // Try to add this image to the icon family.  Ignore images whose sizes
-    // don't map to any ICNS icon type; print warnings and skip images that
-    // fail to encode.
+    // Try to add this image to the icon family. Images whose sizes don't map
+    // to any ICNS icon type (e.g. a provided source that isn't one of the
+    // standard dimensions) are skipped rather than treated as a hard error,
+    // so a single unmappable input can't abort the whole file — leaving
+    // `fill_missing_icon_types_from_source` free to synthesize the standard
+    // sizes from the largest source afterwards.
     match icns::IconType::from_pixel_size_and_density(icon.width(), icon.height(), density) {
       Some(icon_type) => {
         if !family.has_icon_with_type(icon_type) {
           let icon = make_icns_image(icon)?;
           family.add_icon_with_type(&icon, icon_type)?;
         }
-        Ok(())
       }
-      None => Err(io::Error::new(
-        io::ErrorKind::InvalidData,
-        "No matching IconType",
-      )),
+      None => {
+        log::warn!(
+          "no matching ICNS IconType for a {}x{} (density {density}) source; skipping it",
+          icon.width(),
+          icon.height(),
+        );
+      }
     }
+    Ok(())
   }
 
   let mut images_to_resize: Vec<(image::DynamicImage, u32, u32)> = vec![];
+  let mut largest_source: Option<image::DynamicImage> = None;
   for icon_path in settings.icon_files() {
     let icon_path = icon_path?;
 
@@ -73,6 +152,14 @@ pub fn create_icns_file(out_dir: &Path, settings: &Settings) -> crate::Result<Op
     let density = if utils::is_retina(&icon_path) { 2 } else { 1 };
     let (w, h) = icon.dimensions();
     let orig_size = min(w, h);
+
+    if largest_source
+      .as_ref()
+      .map_or(true, |source| min(source.width(), source.height()) < orig_size)
+    {
+      largest_source = Some(icon.clone());
+    }
+
     let next_size_down = 2f32.powf((orig_size as f32).log2().floor()) as u32;
     if orig_size > next_size_down {
       images_to_resize.push((icon, next_size_down, density));
@@ -90,13 +177,20 @@ pub fn create_icns_file(out_dir: &Path, settings: &Settings) -> crate::Result<Op
     add_icon_to_family(icon, density, &mut family)?;
   }
 
+  // Fill in the types listed in `STANDARD_ICNS_SIZES` that are still missing
+  // from the family by downsizing the single largest provided source image.
+  // This lets a user who only supplies one high-resolution (e.g. 1024x1024)
+  // icon still get every size from 16 to 1024px. The 16, 32 and 128 point
+  // @2x types and the density-1 512px type are not synthesized.
+  if let Some(source) = largest_source {
+    fill_missing_icon_types_from_source(&source, &mut family)?;
+  }
+
   if !family.is_empty() {
     fs::create_dir_all(out_dir)?;
-    let mut dest_path = out_dir.to_path_buf();
-    dest_path.push(settings.product_name());
-    dest_path.set_extension("icns");
     let icns_file = BufWriter::new(File::create(&dest_path)?);
     family.write(icns_file)?;
+    write_fingerprint(&dest_path, &fingerprint)?;
     Ok(Some(dest_path))
   } else {
     Err(crate::Error::GenericError(
@@ -105,6 +199,50 @@ pub fn create_icns_file(out_dir: &Path, settings: &Settings) -> crate::Result<Op
   }
 }
 
+// The standard ICNS sizes synthesized from a single high-resolution source,
+// paired with the density their `IconType` maps to. 512 and 1024px map to
+// the @2x (density 2) variants of the 256 and 512 point sizes respectively;
+// everything else is density 1. A size with no matching `IconType` is
+// skipped.
+const STANDARD_ICNS_SIZES: [(u32, u32); 7] = [
+  (16, 1),
+  (32, 1),
+  (64, 1),
+  (128, 1),
+  (256, 1),
+  (512, 2),
+  (1024, 2),
+];
+
+// Downsizes `source` to every standard ICNS size not already present in
+// `family`, skipping sizes the source is too small to produce without
+// upscaling.
+fn fill_missing_icon_types_from_source(
+  source: &image::DynamicImage,
+  family: &mut icns::IconFamily,
+) -> io::Result<()> {
+  let min_dim = min(source.width(), source.height());
+  for &(size, density) in STANDARD_ICNS_SIZES.iter() {
+    if min_dim < size {
+      continue;
+    }
+    let Some(icon_type) = icns::IconType::from_pixel_size_and_density(size, size, density) else {
+      continue;
+    };
+    if family.has_icon_with_type(icon_type) {
+      continue;
+    }
+    let resized = if min_dim == size {
+      source.clone()
+    } else {
+      source.resize_exact(size, size, image::imageops::FilterType::Lanczos3)
+    };
+    let icon = make_icns_image(resized)?;
+    family.add_icon_with_type(&icon, icon_type)?;
+  }
+  Ok(())
+}
+
 // Converts an image::DynamicImage into an icns::Image.
 fn make_icns_image(img: image::DynamicImage) -> io::Result<icns::Image> {
   let pixel_format = match img.color() {
@@ -120,8 +258,130 @@ fn make_icns_image(img: image::DynamicImage) -> io::Result<icns::Image> {
   icns::Image::from_data(pixel_format, img.width(), img.height(), img.into_bytes())
 }
 
+// The standard frame sizes packed into a Windows .ico, in ascending order.
+// 256px is PNG-compressed, as is conventional for ICO (and required past the
+// classic BMP format's 255px limit); the rest use the raw BMP-in-ICO format.
+const ICO_SIZES: [u32; 7] = [16, 24, 32, 48, 64, 128, 256];
+
+// Given a list of icon files, try to produce a multi-resolution Windows .ico
+// file in out_dir and return the path to it. Mirrors `create_icns_file`, but
+// packs every standard size that the sources provide without upscaling into
+// a single .ico instead of an icns::IconFamily. Returns `Ok(None)` if no icon
+// files were provided, and an error if none of them yields a frame.
+//
+// The MSI and NSIS bundlers should call this once per build and point their
+// installer icon / shortcut icon settings at the returned path instead of
+// requiring a hand-authored `.ico` in `tauri.conf.json`.
+pub fn create_ico_file(out_dir: &Path, settings: &Settings) -> crate::Result<Option<PathBuf>> {
+  if settings.icon_files().count() == 0 {
+    return Ok(None);
+  }
+
+  // If one of the icon files is already an ICO file, just use that.
+  for icon_path in settings.icon_files() {
+    let icon_path = icon_path?;
+    if icon_path.extension() == Some(OsStr::new("ico")) {
+      let mut dest_path = out_dir.to_path_buf();
+      dest_path.push(icon_path.file_name().expect("Could not get icon filename"));
+      fs_utils::copy_file(&icon_path, &dest_path)?;
+      return Ok(Some(dest_path));
+    }
+  }
+
+  // Otherwise, read available images so we can pick the best source for
+  // each standard frame size (never upscaling).
+  let mut sources: Vec<image::DynamicImage> = vec![];
+  for icon_path in settings.icon_files() {
+    let icon_path = icon_path?;
+    if icon_path
+      .extension()
+      .map_or(false, |ext| ext == "car" || ext == "icon" || ext == "icns")
+    {
+      continue;
+    }
+    sources.push(image::open(&icon_path)?);
+  }
+
+  let mut icon_dir = ico::IconDir::new(ico::ResourceType::Icon);
+  for &size in ICO_SIZES.iter() {
+    let Some(source) = sources
+      .iter()
+      .filter(|image| min(image.width(), image.height()) >= size)
+      .min_by_key(|image| min(image.width(), image.height()))
+    else {
+      continue;
+    };
+
+    let resized = if min(source.width(), source.height()) == size {
+      source.clone()
+    } else {
+      source.resize_exact(size, size, image::imageops::FilterType::Lanczos3)
+    };
+    let rgba = resized.into_rgba8();
+    let ico_image = ico::IconImage::from_rgba_data(size, size, rgba.into_raw());
+
+    let entry = if size == 256 {
+      ico::IconDirEntry::encode_as_png(&ico_image)
+    } else {
+      ico::IconDirEntry::encode_as_bmp(&ico_image)
+    }
+    .map_err(|e| crate::Error::GenericError(format!("failed to encode {size}px ICO frame: {e}")))?;
+    icon_dir.add_entry(entry);
+  }
+
+  if icon_dir.entries().is_empty() {
+    return Err(crate::Error::GenericError(
+      "No usable Icon files found".to_owned(),
+    ));
+  }
+
+  fs::create_dir_all(out_dir)?;
+  let mut dest_path = out_dir.to_path_buf();
+  dest_path.push(settings.product_name());
+  dest_path.set_extension("ico");
+  let ico_file = BufWriter::new(File::create(&dest_path)?);
+  icon_dir.write(ico_file)?;
+  Ok(Some(dest_path))
+}
+
 /// All the Assets.car code was originally by https://github.com/tauri-apps/tauri/pull/14671/changes
 
+/// The device family actool should compile an Icon Composer `Assets.car` for,
+/// set via `Settings::icon_composer_target_devices` (deserialized from
+/// `bundle.macOS.iconComposer.targetDevices` — see
+/// `bundle::settings::IconComposerConfig`). Defaults to `Mac` alone when a
+/// bundle doesn't configure any, preserving the historical macOS-only
+/// behavior.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum IconComposerTargetDevice {
+  Mac,
+  IPhone,
+  IPad,
+  Tv,
+}
+
+impl IconComposerTargetDevice {
+  // The value actool expects for `--target-device`.
+  fn actool_target_device(self) -> &'static str {
+    match self {
+      IconComposerTargetDevice::Mac => "mac",
+      IconComposerTargetDevice::IPhone => "iphone",
+      IconComposerTargetDevice::IPad => "ipad",
+      IconComposerTargetDevice::Tv => "tv",
+    }
+  }
+
+  // The value actool expects for `--platform`, derived from this device.
+  fn actool_platform(self) -> &'static str {
+    match self {
+      IconComposerTargetDevice::Mac => "macosx",
+      IconComposerTargetDevice::IPhone | IconComposerTargetDevice::IPad => "iphoneos",
+      IconComposerTargetDevice::Tv => "appletvos",
+    }
+  }
+}
+
 /// Creates an Assets.car file from a .icon file if there are any in the settings.
 /// Uses an existing Assets.car file if it exists in the settings.
 /// Returns the path to the Assets.car file.
@@ -151,25 +411,68 @@ pub fn create_assets_car_file(
     return Ok(None);
   };
 
-  // Check actool version - must be >= 26
-  if let Some(version) = get_actool_version() {
+  let target_devices = settings.icon_composer_target_devices();
+  let target_devices: Vec<IconComposerTargetDevice> = if target_devices.is_empty() {
+    vec![IconComposerTargetDevice::Mac]
+  } else {
+    target_devices.to_vec()
+  };
+  let platform = target_devices[0].actool_platform();
+  let minimum_deployment_target = settings
+    .icon_composer_minimum_deployment_target()
+    .unwrap_or("26.0");
+
+  // Check actool version - must be >= 26. Older (or missing) actool can't
+  // compile an Icon Composer `.icon`, so fall back to a classic
+  // `AppIcon.appiconset` instead of dropping the user's icon entirely.
+  let actool_version = get_actool_version();
+  if let Some(version) = &actool_version {
     // Parse the major version number (before the dot)
     let major_version: Option<u32> = version.split('.').next().and_then(|s| s.parse().ok());
 
     if let Some(major) = major_version {
       if major < 26 {
-        log::error!("actool version is less than 26, skipping Assets.car file creation. Please update Xcode to 26 or above and try again.");
-        return Ok(None);
+        log::warn!("actool version is less than 26; falling back to a legacy AppIcon.appiconset. Update Xcode to 26 or above to use Icon Composer icons.");
+        return create_assets_car_file_with_legacy_fallback(
+          out_dir,
+          settings,
+          &icon_composer_icon_path,
+          &target_devices,
+        );
       }
     } else {
-      // If we can't parse the version, return None to be safe
-      log::error!("failed to parse actool version, skipping Assets.car file creation");
-      return Ok(None);
+      log::warn!("failed to parse actool version; falling back to a legacy AppIcon.appiconset");
+      return create_assets_car_file_with_legacy_fallback(
+        out_dir,
+        settings,
+        &icon_composer_icon_path,
+        &target_devices,
+      );
     }
   } else {
-    log::error!("failed to get actool version, skipping Assets.car file creation");
-    // If we can't get the version, return None to be safe
-    return Ok(None);
+    log::warn!("failed to get actool version; falling back to a legacy AppIcon.appiconset");
+    return create_assets_car_file_with_legacy_fallback(
+      out_dir,
+      settings,
+      &icon_composer_icon_path,
+      &target_devices,
+    );
+  }
+
+  // Skip the (expensive) actool invocation entirely if the .icon contents
+  // and the actool version are unchanged since the last time we compiled it.
+  let dest_path = out_dir.join("Assets.car");
+  let mut fingerprint_extra = vec![
+    actool_version.as_deref().unwrap_or("unknown"),
+    minimum_deployment_target,
+  ];
+  for target_device in &target_devices {
+    fingerprint_extra.push(target_device.actool_target_device());
+  }
+  fingerprint_extra.push(platform);
+  let fingerprint = compute_fingerprint(&[icon_composer_icon_path.clone()], &fingerprint_extra)?;
+  if fingerprint_matches(&dest_path, &fingerprint) {
+    return Ok(Some(dest_path));
   }
 
   // Create a temporary directory for actool work
@@ -212,12 +515,14 @@ pub fn create_assets_car_file(
   cmd.arg("NO");
   cmd.arg("--development-region");
   cmd.arg("en");
-  cmd.arg("--target-device");
-  cmd.arg("mac");
+  for target_device in &target_devices {
+    cmd.arg("--target-device");
+    cmd.arg(target_device.actool_target_device());
+  }
   cmd.arg("--minimum-deployment-target");
-  cmd.arg("26.0");
+  cmd.arg(minimum_deployment_target);
   cmd.arg("--platform");
-  cmd.arg("macosx");
+  cmd.arg(platform);
 
   cmd.output_ok()?;
 
@@ -229,11 +534,350 @@ pub fn create_assets_car_file(
   }
 
   // copy to out_dir
-  fs_utils::copy_file(&assets_car_path, &out_dir.join("Assets.car"))?;
+  fs_utils::copy_file(&assets_car_path, &dest_path)?;
+
+  // Persist actool's partial Info.plist (it lives in `temp_dir`, which is
+  // removed once this function returns) so `merge_assets_car_info_plist` can
+  // be called afterwards to fold its keys into the bundle's Info.plist.
+  let generated_info_plist_path = output_path.join("assetcatalog_generated_info.plist");
+  if generated_info_plist_path.exists() {
+    fs_utils::copy_file(
+      &generated_info_plist_path,
+      &assets_car_generated_info_plist_path(out_dir),
+    )?;
+  }
+
+  write_fingerprint(&dest_path, &fingerprint)?;
+
+  Ok(Some(dest_path))
+}
+
+// Where `create_assets_car_file` persists actool's
+// `assetcatalog_generated_info.plist` so it survives past the temporary
+// directory actool ran in.
+fn assets_car_generated_info_plist_path(out_dir: &Path) -> PathBuf {
+  out_dir.join("Assets.car.Info.plist")
+}
 
+/// Parses the partial Info.plist actool generated alongside the Assets.car
+/// produced by `create_assets_car_file` (via `--output-partial-info-plist`).
+/// This contains keys like `CFBundleIconName`, `CFBundlePrimaryIcon` and
+/// accent-color entries that must be merged into the bundle's Info.plist for
+/// the Icon Composer icon to actually be picked up by the system.
+///
+/// Returns `Ok(None)` if `create_assets_car_file` was never run, or ran but
+/// didn't produce an Assets.car (e.g. no `.icon` file was configured).
+pub fn parse_assets_car_info_plist(out_dir: &Path) -> crate::Result<Option<plist::Dictionary>> {
+  let plist_path = assets_car_generated_info_plist_path(out_dir);
+  if !plist_path.exists() {
+    return Ok(None);
+  }
+
+  let value = plist::Value::from_file(&plist_path).map_err(|e| {
+    crate::Error::GenericError(format!(
+      "failed to parse {}: {e}",
+      plist_path.display()
+    ))
+  })?;
+  let dict = value.into_dictionary().ok_or_else(|| {
+    crate::Error::GenericError(format!(
+      "{} did not contain a dictionary",
+      plist_path.display()
+    ))
+  })?;
+  Ok(Some(dict))
+}
+
+/// Merges the keys actool generated for the Assets.car icon (see
+/// `parse_assets_car_info_plist`) into `info_plist`. The merge is additive:
+/// any key the bundler or a user-supplied Info.plist already set is left
+/// untouched.
+///
+/// The `.app`/DMG bundler should call this with its in-progress Info.plist
+/// dictionary right after `create_assets_car_file` succeeds, before writing
+/// `Info.plist` to the bundle, the same way it already merges a
+/// user-supplied `src-tauri/Info.plist`.
+pub fn merge_assets_car_info_plist(
+  out_dir: &Path,
+  info_plist: &mut plist::Dictionary,
+) -> crate::Result<()> {
+  let Some(generated) = parse_assets_car_info_plist(out_dir)? else {
+    return Ok(());
+  };
+  for (key, value) in generated {
+    info_plist.entry(key).or_insert(value);
+  }
+  Ok(())
+}
+
+// The classic `AppIcon.appiconset` idiom/point-size/scale entries for each
+// target device, used by the legacy fallback below. actool rejects a
+// `mac`-idiom entry compiled under `--platform iphoneos`/`appletvos`, so
+// each device family gets its own idiom and its own standard point sizes.
+fn legacy_appiconset_entries(target_device: IconComposerTargetDevice) -> &'static [(&'static str, u32, u32)] {
+  // (idiom, point_size, scale)
+  match target_device {
+    IconComposerTargetDevice::Mac => &[
+      ("mac", 16, 1),
+      ("mac", 16, 2),
+      ("mac", 32, 1),
+      ("mac", 32, 2),
+      ("mac", 128, 1),
+      ("mac", 128, 2),
+      ("mac", 256, 1),
+      ("mac", 256, 2),
+      ("mac", 512, 1),
+      ("mac", 512, 2),
+    ],
+    IconComposerTargetDevice::IPhone => &[
+      ("iphone", 20, 2),
+      ("iphone", 20, 3),
+      ("iphone", 29, 2),
+      ("iphone", 29, 3),
+      ("iphone", 40, 2),
+      ("iphone", 40, 3),
+      ("iphone", 60, 2),
+      ("iphone", 60, 3),
+      ("ios-marketing", 1024, 1),
+    ],
+    IconComposerTargetDevice::IPad => &[
+      ("ipad", 20, 1),
+      ("ipad", 20, 2),
+      ("ipad", 29, 1),
+      ("ipad", 29, 2),
+      ("ipad", 40, 1),
+      ("ipad", 40, 2),
+      ("ipad", 76, 1),
+      ("ipad", 76, 2),
+      ("ios-marketing", 1024, 1),
+    ],
+    IconComposerTargetDevice::Tv => &[
+      ("tv", 400, 1),
+      ("tv", 400, 2),
+      ("tv", 1280, 1),
+      ("tv-marketing", 1280, 1),
+    ],
+  }
+}
+
+#[derive(serde::Serialize)]
+struct AppIconSetContents {
+  images: Vec<AppIconSetImage>,
+  info: AppIconSetInfo,
+}
+
+#[derive(serde::Serialize)]
+struct AppIconSetImage {
+  idiom: &'static str,
+  size: String,
+  scale: String,
+  filename: String,
+}
+
+#[derive(serde::Serialize)]
+struct AppIconSetInfo {
+  version: u32,
+  author: &'static str,
+}
+
+// Recursively searches `dir` for the raster image (PNG/JPEG/TIFF) with the
+// largest minimum dimension, for use as the source of a synthesized legacy
+// AppIcon.appiconset. Returns `Ok(None)` if no raster image was found.
+fn find_best_raster_in_dir(dir: &Path) -> crate::Result<Option<image::DynamicImage>> {
+  let mut best: Option<image::DynamicImage> = None;
+  let mut stack = vec![dir.to_path_buf()];
+  while let Some(current) = stack.pop() {
+    for entry in fs::read_dir(&current)? {
+      let path = entry?.path();
+      if path.is_dir() {
+        stack.push(path);
+        continue;
+      }
+      let is_raster = matches!(
+        path
+          .extension()
+          .and_then(OsStr::to_str)
+          .map(|ext| ext.to_ascii_lowercase())
+          .as_deref(),
+        Some("png") | Some("jpg") | Some("jpeg") | Some("tiff")
+      );
+      if !is_raster {
+        continue;
+      }
+      let Ok(image) = image::open(&path) else {
+        continue;
+      };
+      let min_dim = min(image.width(), image.height());
+      if best
+        .as_ref()
+        .map_or(true, |b| min(b.width(), b.height()) < min_dim)
+      {
+        best = Some(image);
+      }
+    }
+  }
+  Ok(best)
+}
+
+// Synthesizes a classic `AppIcon.appiconset` (with its `Contents.json`) at
+// `appiconset_dir` for `target_device`, downsizing `source` to every
+// standard idiom/point-size/scale entry for that device family that doesn't
+// require upscaling.
+fn create_legacy_app_icon_set(
+  appiconset_dir: &Path,
+  source: &image::DynamicImage,
+  target_device: IconComposerTargetDevice,
+) -> crate::Result<()> {
+  fs::create_dir_all(appiconset_dir)?;
+  let min_dim = min(source.width(), source.height());
+
+  let mut images = vec![];
+  for &(idiom, point_size, scale) in legacy_appiconset_entries(target_device) {
+    let px_size = point_size * scale;
+    if min_dim < px_size {
+      continue;
+    }
+    let filename = format!("icon_{idiom}_{point_size}x{point_size}@{scale}x.png");
+    let resized = if min_dim == px_size {
+      source.clone()
+    } else {
+      source.resize_exact(px_size, px_size, image::imageops::FilterType::Lanczos3)
+    };
+    resized
+      .save(appiconset_dir.join(&filename))
+      .map_err(|e| crate::Error::GenericError(format!("failed to write {filename}: {e}")))?;
+    images.push(AppIconSetImage {
+      idiom,
+      size: format!("{point_size}x{point_size}"),
+      scale: format!("{scale}x"),
+      filename,
+    });
+  }
+
+  if images.is_empty() {
+    return Err(crate::Error::GenericError(
+      "source image is too small to synthesize a legacy AppIcon.appiconset".to_owned(),
+    ));
+  }
+
+  let contents = AppIconSetContents {
+    images,
+    info: AppIconSetInfo {
+      version: 1,
+      author: "xcode",
+    },
+  };
+  let contents_json = serde_json::to_string_pretty(&contents)
+    .map_err(|e| crate::Error::GenericError(format!("failed to serialize Contents.json: {e}")))?;
+  fs::write(appiconset_dir.join("Contents.json"), contents_json)?;
+  Ok(())
+}
+
+// The classic per-platform minimum deployment target to pass to a pre-26
+// actool when compiling the legacy `AppIcon.appiconset` fallback.
+fn legacy_minimum_deployment_target(target_device: IconComposerTargetDevice) -> &'static str {
+  match target_device {
+    IconComposerTargetDevice::Mac => "10.13",
+    IconComposerTargetDevice::IPhone | IconComposerTargetDevice::IPad => "12.0",
+    IconComposerTargetDevice::Tv => "12.0",
+  }
+}
+
+// Builds a classic `AppIcon.appiconset` from the best raster found inside
+// `icon_composer_icon_path` and compiles it with actool, for use when the
+// installed actool predates the Icon Composer pipeline. Only the first of
+// `target_devices` is actually built and compiled for — actool's
+// `--minimum-deployment-target`/`--platform` are singular, so a fallback
+// set spanning multiple device families isn't meaningfully buildable here.
+// Returns `Ok(None)` if no usable raster could be found or actool still
+// fails, in which case the caller should fall back further to
+// `create_icns_file`.
+fn create_assets_car_from_legacy_app_icon_set(
+  out_dir: &Path,
+  icon_composer_icon_path: &Path,
+  target_devices: &[IconComposerTargetDevice],
+) -> crate::Result<Option<PathBuf>> {
+  let Some(source) = find_best_raster_in_dir(icon_composer_icon_path)? else {
+    log::warn!(
+      "no usable raster image found inside {} to synthesize a legacy AppIcon.appiconset",
+      icon_composer_icon_path.display()
+    );
+    return Ok(None);
+  };
+
+  let target_device = target_devices
+    .first()
+    .copied()
+    .unwrap_or(IconComposerTargetDevice::Mac);
+
+  let temp_dir = tempfile::tempdir()
+    .map_err(|e| crate::Error::GenericError(format!("failed to create temp dir: {e}")))?;
+  let xcassets_dir = temp_dir.path().join("Assets.xcassets");
+  let appiconset_dir = xcassets_dir.join("AppIcon.appiconset");
+  create_legacy_app_icon_set(&appiconset_dir, &source, target_device)?;
+
+  let output_path = temp_dir.path().join("out");
+  fs::create_dir_all(&output_path)?;
+
+  let mut cmd = Command::new("actool");
+  cmd.arg(&xcassets_dir);
+  cmd.arg("--compile");
+  cmd.arg(&output_path);
+  cmd.arg("--output-format");
+  cmd.arg("human-readable-text");
+  cmd.arg("--notices");
+  cmd.arg("--warnings");
+  cmd.arg("--app-icon");
+  cmd.arg("AppIcon");
+  cmd.arg("--enable-on-demand-resources");
+  cmd.arg("NO");
+  cmd.arg("--development-region");
+  cmd.arg("en");
+  // The appiconset above was only populated with `target_device`'s idiom
+  // entries (and is compiled under its single `--platform`), so only that
+  // device may be passed here — listing the other requested devices would
+  // have actool expect images for idioms the set doesn't contain.
+  cmd.arg("--target-device");
+  cmd.arg(target_device.actool_target_device());
+  cmd.arg("--minimum-deployment-target");
+  cmd.arg(legacy_minimum_deployment_target(target_device));
+  cmd.arg("--platform");
+  cmd.arg(target_device.actool_platform());
+
+  if cmd.output_ok().is_err() {
+    log::warn!("legacy actool invocation failed while compiling fallback AppIcon.appiconset");
+    return Ok(None);
+  }
+
+  let assets_car_path = output_path.join("Assets.car");
+  if !assets_car_path.exists() {
+    return Ok(None);
+  }
+
+  fs::create_dir_all(out_dir)?;
+  fs_utils::copy_file(&assets_car_path, &out_dir.join("Assets.car"))?;
   Ok(Some(out_dir.join("Assets.car")))
 }
 
+// Tries the legacy `AppIcon.appiconset` fallback, and if that also fails
+// (no usable raster, or actool rejects it), falls back further to a plain
+// `.icns` via `create_icns_file` so the user never ends up with no icon.
+fn create_assets_car_file_with_legacy_fallback(
+  out_dir: &Path,
+  settings: &Settings,
+  icon_composer_icon_path: &Path,
+  target_devices: &[IconComposerTargetDevice],
+) -> crate::Result<Option<PathBuf>> {
+  match create_assets_car_from_legacy_app_icon_set(out_dir, icon_composer_icon_path, target_devices) {
+    Ok(Some(path)) => Ok(Some(path)),
+    Ok(None) => create_icns_file(out_dir, settings),
+    Err(e) => {
+      log::warn!("failed to synthesize legacy AppIcon.appiconset: {e}");
+      create_icns_file(out_dir, settings)
+    }
+  }
+}
+
 #[derive(serde::Deserialize)]
 struct AssetsCarInfo {
   #[serde(rename = "AssetType", default)]
@@ -323,4 +967,90 @@ bundle-version: 24411
   fn test_parse_actool_version_empty() {
     assert!(parse_actool_version("").is_none());
   }
+
+  // Exercises the integration point bundlers are expected to call after
+  // `create_assets_car_file`: fold actool's generated Info.plist keys into
+  // the bundle's own Info.plist without clobbering what's already there.
+  #[test]
+  fn test_merge_assets_car_info_plist_is_additive() {
+    let temp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    let out_dir = temp_dir.path();
+
+    let mut generated = plist::Dictionary::new();
+    generated.insert(
+      "CFBundleIconName".to_owned(),
+      plist::Value::String("AppIcon".to_owned()),
+    );
+    generated.insert(
+      "CFBundlePrimaryIcon".to_owned(),
+      plist::Value::String("AppIcon".to_owned()),
+    );
+    plist::to_file_xml(
+      assets_car_generated_info_plist_path(out_dir),
+      &plist::Value::Dictionary(generated),
+    )
+    .expect("failed to write fixture plist");
+
+    let mut info_plist = plist::Dictionary::new();
+    info_plist.insert(
+      "CFBundleIconName".to_owned(),
+      plist::Value::String("UserOverride".to_owned()),
+    );
+
+    merge_assets_car_info_plist(out_dir, &mut info_plist).expect("merge failed");
+
+    assert_eq!(
+      info_plist.get("CFBundleIconName"),
+      Some(&plist::Value::String("UserOverride".to_owned())),
+      "existing keys must not be clobbered"
+    );
+    assert_eq!(
+      info_plist.get("CFBundlePrimaryIcon"),
+      Some(&plist::Value::String("AppIcon".to_owned())),
+      "missing keys must be merged in"
+    );
+  }
+
+  #[test]
+  fn test_merge_assets_car_info_plist_missing_file_is_a_noop() {
+    let temp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    let mut info_plist = plist::Dictionary::new();
+    merge_assets_car_info_plist(temp_dir.path(), &mut info_plist).expect("merge failed");
+    assert!(info_plist.is_empty());
+  }
+
+  // Exercises `create_ico_file` the way the MSI/NSIS bundlers are expected
+  // to: build a `Settings` from a handful of raster icons and pack them into
+  // a single multi-resolution `.ico`.
+  #[test]
+  fn test_create_ico_file_packs_multiple_resolutions() {
+    let temp_dir = tempfile::tempdir().expect("failed to create temp dir");
+    let icons_dir = temp_dir.path().join("icons");
+    fs::create_dir_all(&icons_dir).expect("failed to create icons dir");
+
+    let mut icon_files = vec![];
+    for &size in &[32u32, 128, 256] {
+      let path = icons_dir.join(format!("icon-{size}.png"));
+      image::DynamicImage::new_rgba8(size, size)
+        .save(&path)
+        .expect("failed to save fixture icon");
+      icon_files.push(path);
+    }
+
+    let settings = Settings::new("TestApp".to_owned(), icon_files, None, Default::default());
+
+    let out_dir = temp_dir.path().join("out");
+    let ico_path = create_ico_file(&out_dir, &settings)
+      .expect("create_ico_file failed")
+      .expect("expected an ico file to be produced");
+
+    assert_eq!(ico_path, out_dir.join("TestApp.ico"));
+    let icon_dir = ico::IconDir::read(File::open(&ico_path).expect("failed to open generated ico"))
+      .expect("failed to parse generated ico");
+    assert_eq!(
+      icon_dir.entries().len(),
+      ICO_SIZES.len(),
+      "expected one frame per standard ICO size"
+    );
+  }
 }
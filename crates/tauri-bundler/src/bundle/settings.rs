@@ -0,0 +1,78 @@
+// Copyright 2016-2019 Cargo-Bundle developers <https://github.com/burtonageo/cargo-bundle>
+// Copyright 2019-2024 Tauri Programme within The Commons Conservancy
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::bundle::macos::icon::IconComposerTargetDevice;
+use std::path::PathBuf;
+
+/// The `bundle.macOS.iconComposer` section of `tauri.conf.json`, controlling
+/// how `macos::icon::create_assets_car_file` invokes actool's Icon Composer
+/// (`.icon`) pipeline.
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IconComposerConfig {
+  /// Device families actool should compile the asset catalog for. Empty
+  /// when omitted, in which case the Icon Composer pipeline compiles for
+  /// `mac` alone, preserving its historical macOS-only behavior.
+  #[serde(default)]
+  pub target_devices: Vec<IconComposerTargetDevice>,
+  /// Overrides the `--minimum-deployment-target` passed to actool when
+  /// compiling the `.icon`. Defaults to `"26.0"` when unset. The legacy
+  /// `AppIcon.appiconset` fallback does not use this value.
+  pub minimum_deployment_target: Option<String>,
+}
+
+/// Bundler settings consumed by `bundle::macos::icon`. This mirrors the
+/// subset of the crate's full `Settings` type that the icon pipeline reads;
+/// other bundler modules (DMG, MSI, NSIS, ...) read additional fields not
+/// modeled here.
+#[derive(Debug, Clone, Default)]
+pub struct Settings {
+  product_name: String,
+  icon_files: Vec<PathBuf>,
+  icons: Option<Vec<String>>,
+  icon_composer: IconComposerConfig,
+}
+
+impl Settings {
+  pub fn new(
+    product_name: String,
+    icon_files: Vec<PathBuf>,
+    icons: Option<Vec<String>>,
+    icon_composer: IconComposerConfig,
+  ) -> Self {
+    Settings {
+      product_name,
+      icon_files,
+      icons,
+      icon_composer,
+    }
+  }
+
+  pub fn product_name(&self) -> &str {
+    &self.product_name
+  }
+
+  pub fn icon_files(&self) -> impl Iterator<Item = crate::Result<PathBuf>> + '_ {
+    self.icon_files.iter().cloned().map(Ok)
+  }
+
+  pub fn icons(&self) -> Option<&Vec<String>> {
+    self.icons.as_ref()
+  }
+
+  /// Device families to compile the Icon Composer `Assets.car` for, from
+  /// `bundle.macOS.iconComposer.targetDevices`. Empty when unconfigured; the
+  /// icon pipeline falls back to `[IconComposerTargetDevice::Mac]` in that
+  /// case.
+  pub fn icon_composer_target_devices(&self) -> &[IconComposerTargetDevice] {
+    &self.icon_composer.target_devices
+  }
+
+  /// The `--minimum-deployment-target` to pass to actool, from
+  /// `bundle.macOS.iconComposer.minimumDeploymentTarget`.
+  pub fn icon_composer_minimum_deployment_target(&self) -> Option<&str> {
+    self.icon_composer.minimum_deployment_target.as_deref()
+  }
+}